@@ -1,8 +1,268 @@
+use std::error::Error;
+use std::fmt;
 use std::iter::Iterator;
 use std::marker::PhantomData;
-use std::mem::size_of;
+use std::mem::{align_of, size_of};
+use std::slice;
 use vk;
 
+/// Marker trait for types that can be safely reinterpreted as a byte slice.
+///
+/// # Safety
+///
+/// A type may only implement `AsBytes` if it has no padding bytes of its own, so that
+/// every one of its `size_of::<T>()` bytes is a meaningful, initialized value. Writing a
+/// type with padding straight into mapped device memory would expose uninitialized bytes
+/// to the driver, so implementing `AsBytes` for such a type is unsound.
+pub unsafe trait AsBytes: Sized {
+    /// Reinterprets `self` as a byte slice.
+    fn as_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self as *const Self as *const u8, size_of::<Self>()) }
+    }
+}
+
+/// Marker trait for types for which every possible bit pattern is a valid value.
+///
+/// # Safety
+///
+/// A type may only implement `FromBytes` if no bit pattern of its `size_of::<T>()` bytes
+/// is invalid, so that bytes read back from mapped device memory - which may have been
+/// written by the driver to anything - can always be reinterpreted as `T`. This rules out
+/// `bool`, `char`, enums with unreachable discriminants, references, and the like.
+pub unsafe trait FromBytes: Sized + Copy {
+    /// Reads a single `Self` out of `bytes`, which must be exactly `size_of::<Self>()` long.
+    fn read_from(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), size_of::<Self>());
+        unsafe { ::std::ptr::read_unaligned(bytes.as_ptr() as *const Self) }
+    }
+}
+
+/// Marker trait for types with an alignment of `1`.
+///
+/// # Safety
+///
+/// A type may only implement `Unaligned` if `align_of::<T>() == 1`.
+pub unsafe trait Unaligned {}
+
+macro_rules! impl_pod {
+    ($($t:ty),* $(,)*) => {
+        $(
+            unsafe impl AsBytes for $t {}
+            unsafe impl FromBytes for $t {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl Unaligned for u8 {}
+unsafe impl Unaligned for i8 {}
+
+// Hand-written impls for the common `vk::*` POD types. Each of these is a `#[repr(C)]`
+// struct made up entirely of other `AsBytes`/`FromBytes` fields with no trailing padding,
+// so reinterpreting them as bytes (and back) is sound.
+unsafe impl AsBytes for vk::Extent2D {}
+unsafe impl FromBytes for vk::Extent2D {}
+unsafe impl AsBytes for vk::Extent3D {}
+unsafe impl FromBytes for vk::Extent3D {}
+unsafe impl AsBytes for vk::Offset2D {}
+unsafe impl FromBytes for vk::Offset2D {}
+unsafe impl AsBytes for vk::Offset3D {}
+unsafe impl FromBytes for vk::Offset3D {}
+
+#[cfg(test)]
+mod pod_trait_tests {
+    use super::*;
+
+    #[test]
+    fn as_bytes_round_trips_through_from_bytes_for_scalars() {
+        let value: u32 = 0xDEAD_BEEF;
+        let bytes = value.as_bytes();
+        assert_eq!(bytes.len(), size_of::<u32>());
+        assert_eq!(u32::read_from(bytes), value);
+    }
+
+    #[repr(align(16))]
+    struct Aligned16([u8; 64]);
+
+    #[test]
+    fn align_copy_from_slice_and_read_into_round_trip_through_padding() {
+        let mut storage = Aligned16([0u8; 64]);
+        let ptr = storage.0.as_mut_ptr() as *mut vk::c_void;
+        // 4-byte u32s laid out with a 16-byte stride, like a std140 scalar array.
+        let mut align = unsafe { Align::<u32>::new(ptr, 16, 64) };
+        align.copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(align.read_into(), vec![1, 2, 3, 4]);
+    }
+
+    #[repr(align(8))]
+    struct Aligned8([u8; 16]);
+
+    #[test]
+    fn typed_reinterprets_the_mapped_bytes_as_elements() {
+        let mut storage = Aligned8([0u8; 16]);
+        let ptr = storage.0.as_mut_ptr();
+        unsafe {
+            (ptr as *mut u64).write(0x0102_0304_0506_0708);
+        }
+        let abs = unsafe { AlignByteSlice::new(ptr as *mut (), 1, 16) };
+        let view = abs.typed::<u64>();
+        assert_eq!(view.len(), 2);
+        assert_eq!(view[0], 0x0102_0304_0506_0708);
+    }
+
+    #[test]
+    #[should_panic(expected = "align_of::<T>()")]
+    fn typed_panics_on_a_pointer_that_does_not_satisfy_align_of_t() {
+        let mut storage = Aligned8([0u8; 16]);
+        // `storage.0` is 8-byte aligned, so offsetting by 1 byte can never be.
+        let misaligned = unsafe { storage.0.as_mut_ptr().add(1) };
+        let abs = unsafe { AlignByteSlice::new(misaligned as *mut (), 1, 8) };
+        let _ = abs.typed::<u64>();
+    }
+}
+
+/// `Padded<T, N>` bakes `N` bytes of trailing padding into `T` at compile time, so a plain
+/// `#[repr(C)]` struct (or a `&[Padded<T, N>]`) already matches a GPU std140/std430 layout
+/// without running an `Align` copy pass.
+///
+/// For example `[Padded<u32, 12>; K]` gets the 16-byte array stride std140 requires for a
+/// scalar array, and `Padded<[f32; 3], 4>` gives a `vec3` its trailing 4 bytes of padding.
+/// Where `Align<T>` picks padding at runtime from a driver-reported alignment, `Padded`
+/// fixes it in the type itself, so it composes with ordinary Rust slices and structs
+/// instead of needing a separate copy step:
+///
+/// ```no_run
+/// use ash::util::Padded;
+/// // `Align::new` needs the mapped pointer and a runtime alignment to lay out a
+/// // `[u32; 4]` with 16-byte stride:
+/// // let mut align = unsafe { Align::<u32>::new(ptr, 16, size) };
+/// // align.copy_from_slice(&data);
+///
+/// // `Padded` gets the same layout for free from the type alone:
+/// let data: [Padded<u32, 12>; 4] = [0u32.into(), 1u32.into(), 2u32.into(), 3u32.into()];
+/// ```
+///
+/// Picking an `N` that leaves the compiler to insert its own hidden tail padding - on top of
+/// `_padding` - is rejected at compile time instead of silently exposing that uninitialized
+/// byte through `as_bytes`:
+///
+/// ```compile_fail
+/// use ash::util::{AsBytes, Padded};
+/// // size_of::<u16>() + 1 == 3, not a multiple of align_of::<u16>() == 2, so the compiler
+/// // would insert one more hidden padding byte that `Padded::new` never writes.
+/// let p: Padded<u16, 1> = Padded::new(5);
+/// let _ = p.as_bytes();
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct Padded<T, const N: usize> {
+    value: T,
+    _padding: [u8; N],
+}
+
+impl<T, const N: usize> Padded<T, N> {
+    pub fn new(value: T) -> Self {
+        Padded {
+            value,
+            _padding: [0u8; N],
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Default, const N: usize> Default for Padded<T, N> {
+    fn default() -> Self {
+        Padded::new(T::default())
+    }
+}
+
+impl<T, const N: usize> From<T> for Padded<T, N> {
+    fn from(value: T) -> Self {
+        Padded::new(value)
+    }
+}
+
+impl<T, const N: usize> ::std::ops::Deref for Padded<T, N> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, const N: usize> ::std::ops::DerefMut for Padded<T, N> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+// `Padded<T, N>`'s `#[repr(C)]` layout is `value` followed by the explicit `_padding: [u8;
+// N]` field, but if `size_of::<T>() + N` doesn't already land on `align_of::<T>()`, the
+// compiler inserts *further*, uninitialized trailing padding to round the struct up - on
+// top of `_padding`, and never written by `Padded::new`/`Default`/`From`. Exposing that via
+// `as_bytes` would read uninitialized memory, so `as_bytes` asserts at compile time that `N`
+// leaves no such hidden tail: calling it with a bad `N` is a compile error. The `AsBytes` impl
+// itself covers every `N`, so code that copies a `Padded<T, N>` as a whole value without
+// calling this `as_bytes` (`Align::copy_from_slice`, writes through `AlignIter`, or the
+// default `as_bytes` of an array of them) is not checked.
+unsafe impl<T: AsBytes, const N: usize> AsBytes for Padded<T, N> {
+    fn as_bytes(&self) -> &[u8] {
+        const {
+            assert!(
+                (size_of::<T>() + N) % align_of::<T>() == 0,
+                "Padded<T, N>'s explicit padding does not round T up to its own alignment; \
+                 the compiler would insert additional, uninitialized trailing padding"
+            );
+        }
+        unsafe { slice::from_raw_parts(self as *const Self as *const u8, size_of::<Self>()) }
+    }
+}
+unsafe impl<T: FromBytes, const N: usize> FromBytes for Padded<T, N> {}
+unsafe impl<T: Unaligned, const N: usize> Unaligned for Padded<T, N> {}
+
+// Arrays never have hidden tail padding: Rust guarantees `size_of::<T>()` is already a
+// multiple of `align_of::<T>()`, so `N` elements packed back to back need no further
+// rounding. This is what lets `Padded<[f32; 3], 4>` (a std140 `vec3`) or a `[Padded<u32,
+// 12>; K]` array feed straight into `Align`/`AlignByteSlice`.
+unsafe impl<T: AsBytes, const N: usize> AsBytes for [T; N] {}
+unsafe impl<T: FromBytes, const N: usize> FromBytes for [T; N] {}
+
+#[cfg(test)]
+mod padded_tests {
+    use super::*;
+
+    #[test]
+    fn new_into_inner_default_and_from_round_trip_through_deref() {
+        let p: Padded<u32, 12> = Padded::new(42);
+        assert_eq!(*p, 42);
+        assert_eq!(p.into_inner(), 42);
+
+        let d: Padded<u32, 12> = Padded::default();
+        assert_eq!(*d, 0);
+
+        let mut f: Padded<u32, 12> = 7u32.into();
+        *f = 9;
+        assert_eq!(*f, 9);
+    }
+
+    #[test]
+    fn size_matches_the_explicit_value_plus_padding_when_n_leaves_no_hidden_tail() {
+        assert_eq!(size_of::<Padded<u32, 12>>(), 16);
+        assert_eq!(size_of::<[Padded<u32, 12>; 4]>(), 64);
+    }
+
+    #[test]
+    fn as_bytes_covers_exactly_size_of_self() {
+        let p: Padded<u32, 12> = Padded::new(0xABCD_1234);
+        let bytes = p.as_bytes();
+        assert_eq!(bytes.len(), size_of::<Padded<u32, 12>>());
+        assert_eq!(&bytes[0..4], &0xABCD_1234u32.to_ne_bytes());
+    }
+}
+
 /// AlignByteSlice is the dynamic alternative to `Align`. Sometimes the user wants to
 /// align slices at runtime. One example would be to align different images in one buffer.
 /// There is usually no indicates of how big an image is at compile time and `AlignByteSlice`
@@ -14,32 +274,170 @@ pub struct AlignByteSlice {
     size: vk::DeviceSize,
 }
 
+/// Error returned when packing slices into an [`AlignByteSlice`] would write past the end
+/// of its backing allocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlignOverflowError {
+    /// The offset at which the write would have started.
+    pub offset: vk::DeviceSize,
+    /// The number of bytes the write would have needed.
+    pub len: vk::DeviceSize,
+    /// The total size of the backing allocation.
+    pub capacity: vk::DeviceSize,
+}
+
+impl fmt::Display for AlignOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "writing {} bytes at offset {} would overflow the backing allocation of size {}",
+            self.len, self.offset, self.capacity
+        )
+    }
+}
+
+impl Error for AlignOverflowError {}
+
 impl AlignByteSlice {
-    pub fn copy_from_slices(&mut self, slices: &[&[u8]]) {
-        self.ptr as *mut u8;
-        let mut current = 0;
-        for slice in slices {
+    /// Packs `slices` back to back into the backing allocation, padding between each one so
+    /// every slice starts on `self.alignment`. Returns the offset each slice landed at, in
+    /// the same order as `slices`, so the caller can later bind or copy each region.
+    pub fn copy_from_slices(
+        &mut self,
+        slices: &[&[u8]],
+    ) -> Result<Vec<vk::DeviceSize>, AlignOverflowError> {
+        let alignment = self.alignment;
+        let slices_with_alignment: Vec<(&[u8], vk::DeviceSize)> =
+            slices.iter().map(|slice| (*slice, alignment)).collect();
+        self.copy_from_slices_aligned(&slices_with_alignment)
+    }
+
+    /// Like [`Self::copy_from_slices`], but lets each slice request its own alignment, so
+    /// e.g. images with different `VkMemoryRequirements::alignment` can share one buffer.
+    /// Returns the offset each slice landed at, in the same order as `slices`.
+    pub fn copy_from_slices_aligned(
+        &mut self,
+        slices: &[(&[u8], vk::DeviceSize)],
+    ) -> Result<Vec<vk::DeviceSize>, AlignOverflowError> {
+        let mut offsets = Vec::with_capacity(slices.len());
+        let mut current: vk::DeviceSize = 0;
+        for (slice, alignment) in slices {
+            current += calc_padding(current, *alignment);
+            let len = slice.len() as vk::DeviceSize;
+            if current + len > self.size {
+                return Err(AlignOverflowError {
+                    offset: current,
+                    len,
+                    capacity: self.size,
+                });
+            }
             unsafe {
-                assert!(current <= self.size, "");
                 let ptr = (self.ptr as *mut u8).offset(current as isize);
-                let raw_slice = ::std::slice::from_raw_parts_mut(ptr, slice.len());
+                let raw_slice = slice::from_raw_parts_mut(ptr, slice.len());
                 raw_slice.copy_from_slice(slice);
-                current += slice.len() as vk::DeviceSize;
-                let padding = current % self.alignment;
-                current += padding;
             }
+            offsets.push(current);
+            current += len;
         }
+        Ok(offsets)
     }
 }
 
 impl AlignByteSlice {
     pub unsafe fn new(ptr: *mut (), alignment: vk::DeviceSize, size: vk::DeviceSize) -> Self {
+        assert!(alignment.is_power_of_two(), "alignment must be a power of two");
         AlignByteSlice {
             ptr,
             size,
             alignment,
         }
     }
+
+    /// Like [`Self::new`], but reads `alignment` and `size` straight off the
+    /// `vk::MemoryRequirements` Vulkan reported for the buffer/image `ptr` is mapped from
+    /// (e.g. via `get_buffer_memory_requirements`/`get_image_memory_requirements`), so the
+    /// byte view can't drift out of sync with what the driver actually demands. Asserts
+    /// that `ptr` satisfies `reqs.alignment`.
+    pub unsafe fn from_memory_requirements(ptr: *mut (), reqs: &vk::MemoryRequirements) -> Self {
+        assert!(
+            ptr as vk::DeviceSize % reqs.alignment == 0,
+            "mapped pointer does not satisfy the alignment reported in VkMemoryRequirements"
+        );
+        AlignByteSlice::new(ptr, reqs.alignment, reqs.size)
+    }
+
+    /// Reinterprets the whole mapped region as a `&[T]`. `T: FromBytes` guarantees that
+    /// whatever bytes happen to be in memory form valid `T` values.
+    pub fn typed<T: FromBytes>(&self) -> &[T] {
+        assert!(
+            self.size as usize % size_of::<T>() == 0,
+            "size must be a multiple of size_of::<T>()"
+        );
+        assert!(
+            self.ptr as usize % align_of::<T>() == 0,
+            "mapped pointer does not satisfy align_of::<T>()"
+        );
+        unsafe {
+            slice::from_raw_parts(self.ptr as *const T, self.size as usize / size_of::<T>())
+        }
+    }
+}
+
+#[cfg(test)]
+mod align_byte_slice_tests {
+    use super::*;
+
+    fn backing(size: usize) -> Vec<u8> {
+        vec![0xAAu8; size]
+    }
+
+    #[test]
+    fn copy_from_slices_returns_sequential_offsets() {
+        let mut buf = backing(64);
+        let mut abs =
+            unsafe { AlignByteSlice::new(buf.as_mut_ptr() as *mut (), 4, buf.len() as vk::DeviceSize) };
+        let offsets = abs.copy_from_slices(&[&[1u8, 2, 3], &[4u8, 5, 6, 7, 8]]).unwrap();
+        assert_eq!(offsets, vec![0, 4]);
+        assert_eq!(&buf[0..3], &[1, 2, 3]);
+        assert_eq!(&buf[4..9], &[4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn copy_from_slices_aligned_honors_per_slice_alignment() {
+        let mut buf = backing(64);
+        let mut abs =
+            unsafe { AlignByteSlice::new(buf.as_mut_ptr() as *mut (), 4, buf.len() as vk::DeviceSize) };
+        let offsets = abs
+            .copy_from_slices_aligned(&[(&[1u8, 2, 3][..], 4), (&[9u8; 2][..], 16)])
+            .unwrap();
+        assert_eq!(offsets, vec![0, 16]);
+    }
+
+    #[test]
+    fn copy_from_slices_aligned_handles_zero_length_slices() {
+        let mut buf = backing(32);
+        let mut abs =
+            unsafe { AlignByteSlice::new(buf.as_mut_ptr() as *mut (), 4, buf.len() as vk::DeviceSize) };
+        let offsets = abs
+            .copy_from_slices_aligned(&[(&[1u8, 2, 3][..], 4), (&[][..], 4), (&[9u8][..], 4)])
+            .unwrap();
+        assert_eq!(offsets, vec![0, 4, 4]);
+    }
+
+    #[test]
+    fn copy_from_slices_aligned_reports_overflow_instead_of_writing_out_of_bounds() {
+        let mut buf = backing(8);
+        let mut abs =
+            unsafe { AlignByteSlice::new(buf.as_mut_ptr() as *mut (), 4, buf.len() as vk::DeviceSize) };
+        let err = abs
+            .copy_from_slices_aligned(&[(&[1u8, 2, 3, 4][..], 4), (&[5u8; 8][..], 4)])
+            .unwrap_err();
+        assert_eq!(err.offset, 4);
+        assert_eq!(err.len, 8);
+        assert_eq!(err.capacity, 8);
+        // The slice that did fit was still written.
+        assert_eq!(&buf[0..4], &[1, 2, 3, 4]);
+    }
 }
 
 /// `Align` handles dynamic alignment. x86 aligns on 4 byte boundries but GPUs
@@ -64,24 +462,166 @@ pub struct AlignIter<'a, T: 'a> {
     current: vk::DeviceSize,
 }
 
-impl<T: Copy> Align<T> {
+impl<T: AsBytes> Align<T> {
     pub fn copy_from_slice(&mut self, slice: &[T]) {
-        use std::slice::from_raw_parts_mut;
         if self.elem_size == size_of::<T>() as u64 {
             unsafe {
-                let mapped_slice = from_raw_parts_mut(self.ptr as *mut T, slice.len());
-                mapped_slice.copy_from_slice(slice);
+                ::std::ptr::copy_nonoverlapping(
+                    slice.as_ptr(),
+                    self.ptr as *mut T,
+                    slice.len(),
+                );
             }
         } else {
             for (i, val) in self.iter_mut().enumerate().take(slice.len()) {
-                *val = slice[i];
+                unsafe {
+                    ::std::ptr::copy_nonoverlapping(&slice[i] as *const T, val as *mut T, 1);
+                }
             }
         }
     }
 }
 
+impl<T: FromBytes> Align<T> {
+    /// Reads the whole aligned region back out of mapped memory into an owned `Vec<T>`,
+    /// stripping the inter-element padding. `T: FromBytes` guarantees that whatever bytes
+    /// the driver left behind form valid `T` values.
+    pub fn read_into(&self) -> Vec<T> {
+        let count = (self.size / self.elem_size) as usize;
+        let mut out = Vec::with_capacity(count);
+        for i in 0..count {
+            unsafe {
+                let ptr = (self.ptr as *const u8).offset((i as vk::DeviceSize * self.elem_size) as isize);
+                let bytes = slice::from_raw_parts(ptr, size_of::<T>());
+                out.push(T::read_from(bytes));
+            }
+        }
+        out
+    }
+}
+
+/// Rounds `value` up to the next multiple of `align`. `align` must be a power of two.
+///
+/// This is checked unconditionally, not just in debug builds: the bit-masking below
+/// silently produces the wrong answer for a non-power-of-two `align` instead of panicking,
+/// so callers that pass through a caller-supplied alignment (`Align::new`,
+/// `copy_from_slices_aligned`) need this to fail loudly in release
+/// builds too.
+pub fn align_up(value: vk::DeviceSize, align: vk::DeviceSize) -> vk::DeviceSize {
+    assert!(align.is_power_of_two(), "alignment must be a power of two");
+    (value + align - 1) & !(align - 1)
+}
+
+/// Rounds `value` down to the previous multiple of `align`. `align` must be a power of two.
+/// See [`align_up`] for why this is an unconditional `assert!` rather than `debug_assert!`.
+pub fn align_down(value: vk::DeviceSize, align: vk::DeviceSize) -> vk::DeviceSize {
+    assert!(align.is_power_of_two(), "alignment must be a power of two");
+    value & !(align - 1)
+}
+
 fn calc_padding(adr: vk::DeviceSize, align: vk::DeviceSize) -> vk::DeviceSize {
-    (align - adr % align) % align
+    align_up(adr, align) - adr
+}
+
+/// The size and alignment requirement of a region of device memory, independent of where it
+/// ends up. This is the same information `vk::MemoryRequirements` carries for a whole
+/// allocation, generalized down to a single value or field so it can be composed with
+/// `extend` the way std430 lays out consecutive struct members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceLayout {
+    pub size: vk::DeviceSize,
+    pub alignment: vk::DeviceSize,
+}
+
+impl DeviceLayout {
+    pub fn new(size: vk::DeviceSize, alignment: vk::DeviceSize) -> Self {
+        DeviceLayout { size, alignment }
+    }
+
+    /// The layout of a single `T`, using Rust's own size and alignment.
+    pub fn for_value<T>() -> Self {
+        DeviceLayout {
+            size: size_of::<T>() as vk::DeviceSize,
+            alignment: ::std::mem::align_of::<T>() as vk::DeviceSize,
+        }
+    }
+
+    /// The layout of `len` contiguous `T`s, using Rust's own size and alignment.
+    pub fn for_slice<T>(len: usize) -> Self {
+        DeviceLayout {
+            size: size_of::<T>() as vk::DeviceSize * len as vk::DeviceSize,
+            alignment: ::std::mem::align_of::<T>() as vk::DeviceSize,
+        }
+    }
+
+    /// Rounds `size` up to a multiple of `alignment`.
+    pub fn pad_to_alignment(self) -> Self {
+        DeviceLayout {
+            size: align_up(self.size, self.alignment),
+            alignment: self.alignment,
+        }
+    }
+
+    /// Appends `other` after `self`, the way std430 lays out one struct field after
+    /// another: `other` is bumped up to its own alignment, and the combined layout takes
+    /// the larger of the two alignments. Returns the new layout together with the offset
+    /// `other` landed at.
+    pub fn extend(self, other: DeviceLayout) -> (DeviceLayout, vk::DeviceSize) {
+        let offset = align_up(self.size, other.alignment);
+        let size = offset + other.size;
+        let alignment = self.alignment.max(other.alignment);
+        (DeviceLayout { size, alignment }, offset)
+    }
+}
+
+/// A sub-range of a larger buffer, carrying the `DeviceLayout` it was allocated with so
+/// callers compose buffer sub-ranges with correct alignment instead of hand-rolling
+/// `offset` arithmetic around `Align::new`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Subbuffer {
+    pub offset: vk::DeviceSize,
+    pub size: vk::DeviceSize,
+    pub layout: DeviceLayout,
+}
+
+impl Subbuffer {
+    pub fn new(offset: vk::DeviceSize, layout: DeviceLayout) -> Self {
+        Subbuffer {
+            offset,
+            size: layout.size,
+            layout,
+        }
+    }
+
+    pub fn end(&self) -> vk::DeviceSize {
+        self.offset + self.size
+    }
+
+    /// Returns the sub-range for `layout`, placed immediately after this one and aligned to
+    /// `layout.alignment` - the same "append a field, report where it went" rule
+    /// `DeviceLayout::extend` uses.
+    pub fn next(&self, layout: DeviceLayout) -> Subbuffer {
+        Subbuffer::new(align_up(self.end(), layout.alignment), layout)
+    }
+
+    /// Splits this sub-range into two at `mid` bytes (relative to `self.offset`), keeping
+    /// this range's alignment for both halves. `mid` must itself be a multiple of
+    /// `self.layout.alignment`, otherwise `second`'s offset wouldn't actually satisfy the
+    /// alignment its `layout` field would go on to claim.
+    pub fn split_at(&self, mid: vk::DeviceSize) -> (Subbuffer, Subbuffer) {
+        assert!(mid <= self.size, "split point out of range");
+        assert!(
+            mid % self.layout.alignment == 0,
+            "split point must be a multiple of the sub-buffer's alignment, otherwise the \
+             second half's offset would not satisfy layout.alignment"
+        );
+        let first = Subbuffer::new(self.offset, DeviceLayout::new(mid, self.layout.alignment));
+        let second = Subbuffer::new(
+            self.offset + mid,
+            DeviceLayout::new(self.size - mid, self.layout.alignment),
+        );
+        (first, second)
+    }
 }
 
 impl<T> Align<T> {
@@ -101,6 +641,28 @@ impl<T> Align<T> {
         }
     }
 
+    /// Constructs an `Align<T>` directly from a `DeviceLayout`, unifying the layout math
+    /// `Align::new` and `DeviceLayout` would otherwise duplicate.
+    pub unsafe fn from_device_layout(ptr: *mut vk::c_void, layout: DeviceLayout) -> Self {
+        Align::new(ptr, layout.alignment, layout.size)
+    }
+
+    /// Like [`Self::new`], but takes `alignment` and `size` from the `vk::MemoryRequirements`
+    /// the driver reported for the buffer/image `ptr` is mapped from (e.g. via
+    /// `get_buffer_memory_requirements`/`get_image_memory_requirements`), instead of having
+    /// the caller recompute `T`'s element stride by hand. Asserts that `ptr` satisfies
+    /// `reqs.alignment`.
+    pub unsafe fn from_memory_requirements(
+        ptr: *mut vk::c_void,
+        reqs: &vk::MemoryRequirements,
+    ) -> Self {
+        assert!(
+            ptr as vk::DeviceSize % reqs.alignment == 0,
+            "mapped pointer does not satisfy the alignment reported in VkMemoryRequirements"
+        );
+        Align::new(ptr, reqs.alignment, reqs.size)
+    }
+
     pub fn iter_mut(&mut self) -> AlignIter<T> {
         AlignIter {
             current: 0,
@@ -109,7 +671,7 @@ impl<T> Align<T> {
     }
 }
 
-impl<'a, T: Copy + 'a> Iterator for AlignIter<'a, T> {
+impl<'a, T: AsBytes + 'a> Iterator for AlignIter<'a, T> {
     type Item = &'a mut T;
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.align.size {
@@ -123,3 +685,144 @@ impl<'a, T: Copy + 'a> Iterator for AlignIter<'a, T> {
         }
     }
 }
+
+#[cfg(test)]
+mod device_layout_tests {
+    use super::*;
+
+    #[test]
+    fn align_up_and_down_round_to_multiples() {
+        assert_eq!(align_up(0, 16), 0);
+        assert_eq!(align_up(1, 16), 16);
+        assert_eq!(align_up(16, 16), 16);
+        assert_eq!(align_up(17, 16), 32);
+
+        assert_eq!(align_down(0, 16), 0);
+        assert_eq!(align_down(15, 16), 0);
+        assert_eq!(align_down(16, 16), 16);
+        assert_eq!(align_down(31, 16), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "power of two")]
+    fn align_up_rejects_non_power_of_two() {
+        align_up(10, 3);
+    }
+
+    #[test]
+    fn device_layout_extend_bumps_to_the_field_alignment_and_widens_the_total() {
+        // A `vec3` (12 bytes, align 4) followed by a `vec4` (16 bytes, align 16): the vec4
+        // must start at offset 16, not 12, and the combined alignment is 16.
+        let vec3 = DeviceLayout::new(12, 4);
+        let vec4 = DeviceLayout::new(16, 16);
+        let (combined, offset) = vec3.extend(vec4);
+        assert_eq!(offset, 16);
+        assert_eq!(combined.size, 32);
+        assert_eq!(combined.alignment, 16);
+    }
+
+    #[test]
+    fn device_layout_extend_is_a_no_op_when_already_aligned() {
+        let a = DeviceLayout::new(16, 16);
+        let b = DeviceLayout::new(4, 4);
+        let (combined, offset) = a.extend(b);
+        assert_eq!(offset, 16);
+        assert_eq!(combined.size, 20);
+        assert_eq!(combined.alignment, 16);
+    }
+
+    #[test]
+    fn pad_to_alignment_rounds_size_up() {
+        let layout = DeviceLayout::new(12, 16).pad_to_alignment();
+        assert_eq!(layout.size, 16);
+    }
+
+    #[test]
+    fn subbuffer_next_honors_its_own_alignment() {
+        let first = Subbuffer::new(0, DeviceLayout::new(12, 4));
+        let second = first.next(DeviceLayout::new(16, 16));
+        assert_eq!(second.offset, 16);
+        assert_eq!(second.size, 16);
+    }
+
+    #[test]
+    fn subbuffer_split_at_preserves_alignment_and_covers_the_whole_range() {
+        let whole = Subbuffer::new(32, DeviceLayout::new(64, 8));
+        let (first, second) = whole.split_at(24);
+        assert_eq!(first.offset, 32);
+        assert_eq!(first.size, 24);
+        assert_eq!(first.layout.alignment, 8);
+        assert_eq!(second.offset, 56);
+        assert_eq!(second.size, 40);
+        assert_eq!(second.layout.alignment, 8);
+        assert_eq!(second.end(), whole.end());
+    }
+
+    #[test]
+    #[should_panic(expected = "split point out of range")]
+    fn subbuffer_split_at_rejects_out_of_range_mid() {
+        let whole = Subbuffer::new(0, DeviceLayout::new(16, 8));
+        whole.split_at(17);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiple of the sub-buffer's alignment")]
+    fn subbuffer_split_at_rejects_a_mid_that_would_misalign_the_second_half() {
+        // 10 is not a multiple of 16, so `second.offset == 10` would not actually satisfy
+        // the alignment its `layout` field would claim.
+        let whole = Subbuffer::new(0, DeviceLayout::new(64, 16));
+        whole.split_at(10);
+    }
+}
+
+#[cfg(test)]
+mod memory_requirements_tests {
+    use super::*;
+
+    #[repr(align(16))]
+    struct Aligned16([u8; 32]);
+
+    fn reqs(alignment: vk::DeviceSize, size: vk::DeviceSize) -> vk::MemoryRequirements {
+        vk::MemoryRequirements {
+            size,
+            alignment,
+            memory_type_bits: 0,
+        }
+    }
+
+    #[test]
+    fn align_from_memory_requirements_adopts_the_reported_alignment_and_size() {
+        let mut storage = Aligned16([0; 32]);
+        let ptr = storage.0.as_mut_ptr() as *mut vk::c_void;
+        let align: Align<u32> = unsafe { Align::from_memory_requirements(ptr, &reqs(16, 32)) };
+        assert_eq!(align.size, 32);
+        assert_eq!(align.elem_size, 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not satisfy the alignment")]
+    fn align_from_memory_requirements_rejects_a_pointer_that_does_not_satisfy_reqs_alignment() {
+        let mut storage = Aligned16([0; 32]);
+        // Offsetting by one byte can't still be 16-byte aligned.
+        let ptr = unsafe { storage.0.as_mut_ptr().add(1) } as *mut vk::c_void;
+        let _: Align<u32> = unsafe { Align::from_memory_requirements(ptr, &reqs(16, 31)) };
+    }
+
+    #[test]
+    fn align_byte_slice_from_memory_requirements_adopts_the_reported_alignment_and_size() {
+        let mut storage = Aligned16([0; 32]);
+        let ptr = storage.0.as_mut_ptr() as *mut ();
+        let slice = unsafe { AlignByteSlice::from_memory_requirements(ptr, &reqs(16, 32)) };
+        assert_eq!(slice.alignment, 16);
+        assert_eq!(slice.size, 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not satisfy the alignment")]
+    fn align_byte_slice_from_memory_requirements_rejects_a_pointer_that_does_not_satisfy_reqs_alignment(
+    ) {
+        let mut storage = Aligned16([0; 32]);
+        let ptr = unsafe { storage.0.as_mut_ptr().add(1) } as *mut ();
+        let _ = unsafe { AlignByteSlice::from_memory_requirements(ptr, &reqs(16, 31)) };
+    }
+}